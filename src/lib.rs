@@ -1,4 +1,5 @@
-use std::fmt::{Display, Formatter};
+use std::cell::Cell;
+use std::fmt::{self, Alignment, Debug, Display, Formatter, Write as _};
 
 /// Printable wrapper.
 #[derive(Clone, Copy)]
@@ -7,6 +8,7 @@ pub struct Printable<'a, T> {
     sep: &'a str,
     left_bound: &'a str,
     right_bound: &'a str,
+    overflow_marker: &'a str,
 }
 
 impl<'a, T> Printable<'a, T>
@@ -29,6 +31,7 @@ impl<'a, T> Printable<'a, T>
             data,
             left_bound,
             right_bound,
+            overflow_marker,
             ..
         } = self;
         Printable {
@@ -36,6 +39,7 @@ impl<'a, T> Printable<'a, T>
             sep,
             left_bound,
             right_bound,
+            overflow_marker,
         }
     }
 
@@ -57,6 +61,7 @@ impl<'a, T> Printable<'a, T>
             data,
             right_bound,
             sep,
+            overflow_marker,
             ..
         } = self;
         Printable {
@@ -64,6 +69,7 @@ impl<'a, T> Printable<'a, T>
             sep,
             left_bound,
             right_bound,
+            overflow_marker,
         }
     }
 
@@ -85,6 +91,7 @@ impl<'a, T> Printable<'a, T>
             data,
             left_bound,
             sep,
+            overflow_marker,
             ..
         } = self;
         Printable {
@@ -92,6 +99,38 @@ impl<'a, T> Printable<'a, T>
             sep,
             left_bound,
             right_bound,
+            overflow_marker,
+        }
+    }
+
+    /// Customizes the marker inserted before the right bound when the formatter's precision
+    /// (e.g. `{:.3}`) truncates the output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintable;
+    ///
+    /// let v = vec![1, 2, 3, 4, 5];
+    /// assert_eq!(format!("{:.3}", v.iter().printable()), "[1, 2, 3, …]");
+    /// assert_eq!(format!("{:.3}", v.iter().printable().with_overflow_marker("...")), "[1, 2, 3, ...]")
+    /// ```
+    pub fn with_overflow_marker<'b>(self, overflow_marker: &'b str) -> Printable<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            left_bound,
+            right_bound,
+            sep,
+            ..
+        } = self;
+        Printable {
+            data,
+            sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
         }
     }
 }
@@ -101,7 +140,9 @@ impl<'a, T> Printable<'a, T>
 /// # Warning
 ///
 /// Avoid creating [`Printable`] from memory-owning iterators such as [`std::vec::IntoIter`],
-/// since it clones the owned data every time [`Display::fmt`] is called.
+/// since it clones the owned data every time [`Display::fmt`] is called. Use
+/// [`PrintableOnce`](AsPrintableOnce::printable_once) instead if the iterator shouldn't or
+/// can't be cloned.
 ///
 /// # Examples
 ///
@@ -128,6 +169,7 @@ pub trait AsPrintable: Iterator + Clone
             sep: ", ",
             left_bound: "[",
             right_bound: "]",
+            overflow_marker: "…",
         }
     }
 }
@@ -149,30 +191,863 @@ impl<'a, T> From<T> for Printable<'a, T::IntoIter>
     }
 }
 
+/// Formatter adapter that indents every line written through it by one level, used to render
+/// nested [`Printable`]s under `{:#}` (pretty) mode the same way [`std::fmt::Formatter::debug_list`]
+/// indents nested [`std::fmt::Debug`] output.
+struct PadAdapter<'a, W: ?Sized> {
+    writer: &'a mut W,
+    on_newline: bool,
+}
+
+impl<'a, W: fmt::Write + ?Sized> PadAdapter<'a, W> {
+    fn new(writer: &'a mut W) -> Self {
+        Self { writer, on_newline: true }
+    }
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for PadAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for segment in s.split_inclusive('\n') {
+            if self.on_newline {
+                self.writer.write_str("    ")?;
+            }
+            self.on_newline = segment.ends_with('\n');
+            self.writer.write_str(segment)?;
+        }
+        Ok(())
+    }
+}
+
+/// Bounds, separator and overflow marker shared by [`write_body`]'s callers.
+struct BoundsConfig<'a> {
+    sep: &'a str,
+    left_bound: &'a str,
+    right_bound: &'a str,
+    overflow_marker: &'a str,
+}
+
+/// Writes the bound/separator/overflow-marker-joined body shared by [`Printable`] and its
+/// siblings, honoring `alternate` (multi-line, indented, laid out like [`std`]'s `Debug`
+/// builders) and `precision` (max element count, unlike [`std`]'s builders, which pass it on to
+/// each element).
+fn write_body<W, I>(
+    writer: &mut W,
+    mut iterator: I,
+    config: &BoundsConfig,
+    alternate: bool,
+    precision: Option<usize>,
+) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+        I: Iterator,
+        I::Item: Display,
+{
+    let &BoundsConfig { sep, left_bound, right_bound, overflow_marker } = config;
+    writer.write_str(left_bound)?;
+    let mut wrote_any = false;
+    if alternate {
+        let mut count = 0;
+        loop {
+            if precision.is_some_and(|limit| count >= limit) {
+                if iterator.next().is_some() {
+                    writer.write_str("\n")?;
+                    write!(PadAdapter::new(&mut *writer), "{overflow_marker}{}", sep.trim_end())?;
+                    wrote_any = true;
+                }
+                break;
+            }
+            match iterator.next() {
+                Some(v) => {
+                    writer.write_str("\n")?;
+                    write!(PadAdapter::new(&mut *writer), "{v:#}{}", sep.trim_end())?;
+                    wrote_any = true;
+                    count += 1;
+                }
+                None => break,
+            }
+        }
+        if wrote_any {
+            writer.write_str("\n")?;
+        }
+    } else {
+        let mut truncated = false;
+        let mut count = 0;
+        loop {
+            if precision.is_some_and(|limit| count >= limit) {
+                truncated = iterator.next().is_some();
+                break;
+            }
+            match iterator.next() {
+                Some(v) => {
+                    if wrote_any {
+                        writer.write_str(sep)?;
+                    }
+                    write!(writer, "{v}")?;
+                    wrote_any = true;
+                    count += 1;
+                }
+                None => break,
+            }
+        }
+        if truncated {
+            if wrote_any {
+                writer.write_str(sep)?;
+            }
+            writer.write_str(overflow_marker)?;
+        }
+    }
+    writer.write_str(right_bound)
+}
+
+/// Pads an already-rendered body to `f`'s width, honoring `f`'s fill character and alignment
+/// (defaulting to left-aligned, matching [`str`]'s `Display` impl).
+fn pad_to_width(f: &mut Formatter, body: &str) -> fmt::Result {
+    let width = match f.width() {
+        Some(width) => width,
+        None => return f.write_str(body),
+    };
+    let len = body.chars().count();
+    if len >= width {
+        return f.write_str(body);
+    }
+    let diff = width - len;
+    let fill = f.fill();
+    let (left, right) = match f.align() {
+        Some(Alignment::Right) => (diff, 0),
+        Some(Alignment::Center) => (diff / 2, diff - diff / 2),
+        _ => (0, diff),
+    };
+    for _ in 0..left {
+        f.write_char(fill)?;
+    }
+    f.write_str(body)?;
+    for _ in 0..right {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
 impl<'a, T> Display for Printable<'a, T>
     where
         T: Clone + Iterator,
         T::Item: Display
 {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result
+    /// Honors the alternate, precision, width, fill and alignment [`Formatter`] flags (sign and
+    /// zero-padding flags are ignored): `{:#}` switches to the multi-line, indented rendering
+    /// used by [`std`]'s `Debug` builders; `{:.N}` caps the output at `N` elements, appending
+    /// [`overflow_marker`](Printable::with_overflow_marker) when more were available; and
+    /// `width`/`fill`/`align` pad the rendered block as a whole.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintable;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// assert_eq!(format!("{:#}", v.iter().printable()), "[\n    1,\n    2,\n    3,\n]");
+    /// assert_eq!(format!("{:.2}", v.iter().printable()), "[1, 2, …]");
+    /// assert_eq!(format!("{:*<8}", v.iter().printable()), "[1, 2, 3]");
+    /// assert_eq!(format!("{:*<10}", v.iter().printable()), "[1, 2, 3]*");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        let &Self { ref data, sep, left_bound, right_bound, overflow_marker } = self;
+        let config = BoundsConfig { sep, left_bound, right_bound, overflow_marker };
+        let alternate = f.alternate();
+        let precision = f.precision();
+        if f.width().is_some() {
+            let mut body = String::new();
+            write_body(&mut body, data.clone(), &config, alternate, precision)?;
+            pad_to_width(f, &body)
+        } else {
+            write_body(f, data.clone(), &config, alternate, precision)
+        }
+    }
+}
+
+/// Wraps a value in a [`Display`] adapter that delegates to its [`Debug`] impl, so
+/// [`write_body`] can join `Debug`-rendered elements the same way it joins `Display`-rendered
+/// ones.
+struct AsDebug<T>(T);
+
+impl<T: Debug> Display for AsDebug<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#?}", self.0)
+        } else {
+            write!(f, "{:?}", self.0)
+        }
+    }
+}
+
+impl<'a, T> Debug for Printable<'a, T>
+    where
+        T: Clone + Iterator,
+        T::Item: Debug,
+{
+    /// Renders each element via its [`Debug`] impl instead of [`Display`], sharing the same
+    /// separator/bound configuration. Honors the same `{:#?}`, `{:.N}` and
+    /// `width`/`fill`/`align` flags as [`Printable`]'s `Display` impl.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintable;
+    ///
+    /// let v = vec!["a", "b"];
+    /// assert_eq!(format!("{:?}", v.iter().printable()), r#"["a", "b"]"#);
+    /// assert_eq!(format!("{:#?}", v.iter().printable()), "[\n    \"a\",\n    \"b\",\n]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        let &Self { ref data, sep, left_bound, right_bound, overflow_marker } = self;
+        let config = BoundsConfig { sep, left_bound, right_bound, overflow_marker };
+        let items = data.clone().map(AsDebug);
+        let alternate = f.alternate();
+        let precision = f.precision();
+        if f.width().is_some() {
+            let mut body = String::new();
+            write_body(&mut body, items, &config, alternate, precision)?;
+            pad_to_width(f, &body)
+        } else {
+            write_body(f, items, &config, alternate, precision)
+        }
+    }
+}
+
+/// Printable wrapper that renders each item with a user-supplied closure instead of
+/// requiring `Item: Display`.
+#[derive(Clone)]
+pub struct PrintableWith<'a, T, F> {
+    data: T,
+    format_item: F,
+    sep: &'a str,
+    left_bound: &'a str,
+    right_bound: &'a str,
+}
+
+impl<'a, T, F> PrintableWith<'a, T, F>
+{
+    /// Customizes separator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableWith;
+    ///
+    /// let v = vec![1.5, 2.25, 3.0];
+    /// let p = v.iter().printable_with(|x, f| f(&format_args!("{x:.1}")));
+    /// assert_eq!(format!("{}", p), "[1.5, 2.2, 3.0]");
+    /// assert_eq!(format!("{}", p.with_separator(".")), "[1.5.2.2.3.0]")
+    /// ```
+    pub fn with_separator<'b>(self, sep: &'b str) -> PrintableWith<'b, T, F>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            format_item,
+            left_bound,
+            right_bound,
+            ..
+        } = self;
+        PrintableWith {
+            data,
+            format_item,
+            sep,
+            left_bound,
+            right_bound,
+        }
+    }
+
+    /// Customizes left bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableWith;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// let p = v.iter().printable_with(|x, f| f(x));
+    /// assert_eq!(format!("{}", p.with_left_bound("{")), "{1, 2, 3]")
+    /// ```
+    pub fn with_left_bound<'b>(self, left_bound: &'b str) -> PrintableWith<'b, T, F>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            format_item,
+            right_bound,
+            sep,
+            ..
+        } = self;
+        PrintableWith {
+            data,
+            format_item,
+            sep,
+            left_bound,
+            right_bound,
+        }
+    }
+
+    /// Customizes right bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableWith;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// let p = v.iter().printable_with(|x, f| f(x));
+    /// assert_eq!(format!("{}", p.with_right_bound("}")), "[1, 2, 3}")
+    /// ```
+    pub fn with_right_bound<'b>(self, right_bound: &'b str) -> PrintableWith<'b, T, F>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            format_item,
+            left_bound,
+            sep,
+            ..
+        } = self;
+        PrintableWith {
+            data,
+            format_item,
+            sep,
+            left_bound,
+            right_bound,
+        }
+    }
+}
+
+/// Wrap iterators into [`PrintableWith`], deferring the rendering of each item to a
+/// user-supplied closure.
+///
+/// Unlike [`AsPrintable`], this does not require `Item: Display`: the closure receives each
+/// item together with a callback that writes a [`Display`] value straight into the
+/// formatter, so items can be rendered on the fly (e.g. with fixed precision, as hex, or by
+/// formatting a tuple) without an intermediate allocation.
+///
+/// # Examples
+///
+/// ```rust
+/// use printable::AsPrintableWith;
+///
+/// let v = vec![(1, "a"), (2, "b")];
+/// let p = v.iter().printable_with(|(n, s), f| f(&format_args!("{n}:{s}")));
+/// assert_eq!(format!("{}", p), "[1:a, 2:b]");
+/// ```
+pub trait AsPrintableWith: Iterator + Clone
+{
+    /// Wrap custom struct that can produce printable iterator into [`PrintableWith`], using
+    /// `format_item` to render each element.
+    fn printable_with<F>(self, format_item: F) -> PrintableWith<'static, Self, F>
+        where F: FnMut(Self::Item, &mut dyn FnMut(&dyn Display) -> fmt::Result) -> fmt::Result
+    {
+        PrintableWith {
+            data: self,
+            format_item,
+            sep: ", ",
+            left_bound: "[",
+            right_bound: "]",
+        }
+    }
+}
+
+impl<T> AsPrintableWith for T
+    where
+        T: Iterator + Clone,
+{}
+
+impl<'a, T, F> Display for PrintableWith<'a, T, F>
+    where
+        T: Clone + Iterator,
+        F: Clone + FnMut(T::Item, &mut dyn FnMut(&dyn Display) -> fmt::Result) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
     {
         let Self {
             data,
+            format_item,
             sep,
             left_bound,
             right_bound
         } = self;
         f.write_str(left_bound)?;
         let mut iterator = data.clone();
+        let mut format_item = format_item.clone();
         if let Some(v) = iterator.next()
         {
-            v.fmt(f)?;
+            format_item(v, &mut |d: &dyn Display| d.fmt(f))?;
             for v in iterator
             {
                 f.write_str(sep)?;
-                v.fmt(f)?
+                format_item(v, &mut |d: &dyn Display| d.fmt(f))?;
             }
         }
         f.write_str(right_bound)
     }
+}
+
+/// Printable wrapper for iterators of `(K, V)` pairs, rendering them map-style
+/// (`{k: v, k: v}`) instead of joining a flat sequence.
+#[derive(Clone, Copy)]
+pub struct PrintableMap<'a, T> {
+    data: T,
+    sep: &'a str,
+    kv_sep: &'a str,
+    left_bound: &'a str,
+    right_bound: &'a str,
+    overflow_marker: &'a str,
+}
+
+impl<'a, T> PrintableMap<'a, T>
+{
+    /// Customizes entry separator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableMap;
+    ///
+    /// let v = vec![(1, "a"), (2, "b")];
+    /// assert_eq!(format!("{}", v.iter().copied().printable_map()), "{1: a, 2: b}");
+    /// assert_eq!(format!("{}", v.iter().copied().printable_map().with_separator(" | ")), "{1: a | 2: b}")
+    /// ```
+    pub fn with_separator<'b>(self, sep: &'b str) -> PrintableMap<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            kv_sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+            ..
+        } = self;
+        PrintableMap {
+            data,
+            sep,
+            kv_sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+        }
+    }
+
+    /// Customizes key-value separator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableMap;
+    ///
+    /// let v = vec![(1, "a"), (2, "b")];
+    /// assert_eq!(format!("{}", v.iter().copied().printable_map()), "{1: a, 2: b}");
+    /// assert_eq!(format!("{}", v.iter().copied().printable_map().with_kv_separator(" => ")), "{1 => a, 2 => b}")
+    /// ```
+    pub fn with_kv_separator<'b>(self, kv_sep: &'b str) -> PrintableMap<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+            ..
+        } = self;
+        PrintableMap {
+            data,
+            sep,
+            kv_sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+        }
+    }
+
+    /// Customizes left bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableMap;
+    ///
+    /// let v = vec![(1, "a"), (2, "b")];
+    /// assert_eq!(format!("{}", v.iter().copied().printable_map()), "{1: a, 2: b}");
+    /// assert_eq!(format!("{}", v.iter().copied().printable_map().with_left_bound("(")), "(1: a, 2: b}")
+    /// ```
+    pub fn with_left_bound<'b>(self, left_bound: &'b str) -> PrintableMap<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            sep,
+            kv_sep,
+            right_bound,
+            overflow_marker,
+            ..
+        } = self;
+        PrintableMap {
+            data,
+            sep,
+            kv_sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+        }
+    }
+
+    /// Customizes right bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableMap;
+    ///
+    /// let v = vec![(1, "a"), (2, "b")];
+    /// assert_eq!(format!("{}", v.iter().copied().printable_map()), "{1: a, 2: b}");
+    /// assert_eq!(format!("{}", v.iter().copied().printable_map().with_right_bound(")")), "{1: a, 2: b)")
+    /// ```
+    pub fn with_right_bound<'b>(self, right_bound: &'b str) -> PrintableMap<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            sep,
+            kv_sep,
+            left_bound,
+            overflow_marker,
+            ..
+        } = self;
+        PrintableMap {
+            data,
+            sep,
+            kv_sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+        }
+    }
+
+    /// Customizes the marker inserted before the right bound when the formatter's precision
+    /// truncates the output (see [`Printable::with_overflow_marker`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableMap;
+    ///
+    /// let v = vec![(1, "a"), (2, "b"), (3, "c")];
+    /// assert_eq!(format!("{:.2}", v.iter().copied().printable_map()), "{1: a, 2: b, …}");
+    /// assert_eq!(format!("{:.2}", v.iter().copied().printable_map().with_overflow_marker("...")), "{1: a, 2: b, ...}")
+    /// ```
+    pub fn with_overflow_marker<'b>(self, overflow_marker: &'b str) -> PrintableMap<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            sep,
+            kv_sep,
+            left_bound,
+            right_bound,
+            ..
+        } = self;
+        PrintableMap {
+            data,
+            sep,
+            kv_sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+        }
+    }
+}
+
+/// Wraps a `(K, V)` pair in a single [`Display`] value so [`write_body`] can join map entries
+/// the same way it joins flat [`Printable`] elements.
+struct KvEntry<'a, K, V> {
+    k: K,
+    v: V,
+    kv_sep: &'a str,
+}
+
+impl<K: Display, V: Display> Display for KvEntry<'_, K, V> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Self { k, v, kv_sep } = self;
+        if f.alternate() {
+            write!(f, "{k:#}{kv_sep}{v:#}")
+        } else {
+            write!(f, "{k}{kv_sep}{v}")
+        }
+    }
+}
+
+/// Wrap iterators of `(K, V)` pairs into [`PrintableMap`].
+///
+/// # Examples
+///
+/// ```rust
+/// use printable::AsPrintableMap;
+/// use std::collections::BTreeMap;
+///
+/// let m = BTreeMap::from([(1, "a"), (2, "b")]);
+/// assert_eq!(format!("{}", m.iter().map(|(&k, &v)| (k, v)).printable_map()), "{1: a, 2: b}");
+///
+/// let m: BTreeMap<usize, &str> = BTreeMap::new();
+/// assert_eq!(format!("{}", m.iter().map(|(&k, &v)| (k, v)).printable_map()), "{}")
+/// ```
+pub trait AsPrintableMap<K, V>: Iterator<Item=(K, V)> + Clone
+    where
+        K: Display,
+        V: Display,
+{
+    /// Wrap custom struct that can produce a printable iterator of pairs into [`PrintableMap`].
+    fn printable_map(self) -> PrintableMap<'static, Self> {
+        PrintableMap {
+            data: self,
+            sep: ", ",
+            kv_sep: ": ",
+            left_bound: "{",
+            right_bound: "}",
+            overflow_marker: "…",
+        }
+    }
+}
+
+impl<T, K, V> AsPrintableMap<K, V> for T
+    where
+        T: Iterator<Item=(K, V)> + Clone,
+        K: Display,
+        V: Display,
+{}
+
+impl<'a, T, K, V> Display for PrintableMap<'a, T>
+    where
+        T: Clone + Iterator<Item=(K, V)>,
+        K: Display,
+        V: Display,
+{
+    /// Honors the same `{:#}`, `{:.N}` and `width`/`fill`/`align` flags as [`Printable`]'s
+    /// `Display` impl.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        let &Self { ref data, sep, kv_sep, left_bound, right_bound, overflow_marker } = self;
+        let config = BoundsConfig { sep, left_bound, right_bound, overflow_marker };
+        let entries = data.clone().map(|(k, v)| KvEntry { k, v, kv_sep });
+        let alternate = f.alternate();
+        let precision = f.precision();
+        if f.width().is_some() {
+            let mut body = String::new();
+            write_body(&mut body, entries, &config, alternate, precision)?;
+            pad_to_width(f, &body)
+        } else {
+            write_body(f, entries, &config, alternate, precision)
+        }
+    }
+}
+
+/// Printable wrapper for iterators that cannot or should not be [`Clone`]d, such as
+/// [`std::vec::IntoIter`] or [`Vec::drain`].
+///
+/// Unlike [`Printable`], this drains its iterator exactly once, on the first call to
+/// [`Display::fmt`], instead of cloning it on every call.
+pub struct PrintableOnce<'a, T> {
+    data: Cell<Option<T>>,
+    sep: &'a str,
+    left_bound: &'a str,
+    right_bound: &'a str,
+    overflow_marker: &'a str,
+}
+
+impl<'a, T> PrintableOnce<'a, T>
+{
+    /// Customizes separator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableOnce;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// assert_eq!(format!("{}", v.into_iter().printable_once().with_separator(".")), "[1.2.3]")
+    /// ```
+    pub fn with_separator<'b>(self, sep: &'b str) -> PrintableOnce<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            left_bound,
+            right_bound,
+            overflow_marker,
+            ..
+        } = self;
+        PrintableOnce {
+            data,
+            sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+        }
+    }
+
+    /// Customizes left bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableOnce;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// assert_eq!(format!("{}", v.into_iter().printable_once().with_left_bound("{")), "{1, 2, 3]")
+    /// ```
+    pub fn with_left_bound<'b>(self, left_bound: &'b str) -> PrintableOnce<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            right_bound,
+            sep,
+            overflow_marker,
+            ..
+        } = self;
+        PrintableOnce {
+            data,
+            sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+        }
+    }
+
+    /// Customizes right bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableOnce;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// assert_eq!(format!("{}", v.into_iter().printable_once().with_right_bound("}")), "[1, 2, 3}")
+    /// ```
+    pub fn with_right_bound<'b>(self, right_bound: &'b str) -> PrintableOnce<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            left_bound,
+            sep,
+            overflow_marker,
+            ..
+        } = self;
+        PrintableOnce {
+            data,
+            sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+        }
+    }
+
+    /// Customizes the marker inserted before the right bound when the formatter's precision
+    /// truncates the output (see [`Printable::with_overflow_marker`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use printable::AsPrintableOnce;
+    ///
+    /// let v = vec![1, 2, 3, 4, 5];
+    /// assert_eq!(format!("{:.3}", v.into_iter().printable_once()), "[1, 2, 3, …]")
+    /// ```
+    pub fn with_overflow_marker<'b>(self, overflow_marker: &'b str) -> PrintableOnce<'b, T>
+        where 'a: 'b
+    {
+        let Self {
+            data,
+            left_bound,
+            right_bound,
+            sep,
+            ..
+        } = self;
+        PrintableOnce {
+            data,
+            sep,
+            left_bound,
+            right_bound,
+            overflow_marker,
+        }
+    }
+}
+
+/// Wrap iterators into [`PrintableOnce`], without requiring [`Clone`].
+///
+/// # Examples
+///
+/// ```rust
+/// use printable::AsPrintableOnce;
+///
+/// let v = vec![1, 2, 3];
+/// assert_eq!(format!("{}", v.into_iter().printable_once()), "[1, 2, 3]");
+/// ```
+pub trait AsPrintableOnce: Iterator
+    where
+        Self::Item: Display,
+{
+    /// Wrap custom struct that can produce a printable iterator into [`PrintableOnce`].
+    fn printable_once(self) -> PrintableOnce<'static, Self>
+        where Self: Sized
+    {
+        PrintableOnce {
+            data: Cell::new(Some(self)),
+            sep: ", ",
+            left_bound: "[",
+            right_bound: "]",
+            overflow_marker: "…",
+        }
+    }
+}
+
+impl<T> AsPrintableOnce for T
+    where
+        T: Iterator,
+        T::Item: Display,
+{}
+
+impl<'a, T> Display for PrintableOnce<'a, T>
+    where
+        T: Iterator,
+        T::Item: Display,
+{
+    /// Drains the wrapped iterator on the first call; panics on any later call, since the
+    /// data has already been consumed.
+    ///
+    /// Honors the same `{:#}`, `{:.N}` and `width`/`fill`/`align` flags as [`Printable`]'s
+    /// `Display` impl.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// use printable::AsPrintableOnce;
+    ///
+    /// let p = vec![1, 2, 3].into_iter().printable_once();
+    /// assert_eq!(format!("{p}"), "[1, 2, 3]");
+    /// format!("{p}"); // panics: already drained
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        let &Self { ref data, sep, left_bound, right_bound, overflow_marker } = self;
+        let iterator = data.take().expect("PrintableOnce::fmt called more than once");
+        let config = BoundsConfig { sep, left_bound, right_bound, overflow_marker };
+        let alternate = f.alternate();
+        let precision = f.precision();
+        if f.width().is_some() {
+            let mut body = String::new();
+            write_body(&mut body, iterator, &config, alternate, precision)?;
+            pad_to_width(f, &body)
+        } else {
+            write_body(f, iterator, &config, alternate, precision)
+        }
+    }
 }
\ No newline at end of file